@@ -1,7 +1,7 @@
 use core::ptr::NonNull;
 use core::marker::PhantomData;
 use core::mem::{align_of, size_of};
-use alloc::alloc::{Layout, alloc, handle_alloc_error};
+use alloc::alloc::{Layout, alloc, dealloc, handle_alloc_error};
 
 
 #[cfg(feature = "compat")]
@@ -110,6 +110,28 @@ impl<'a, T, const N: Index> RingBuffer<'a,T,N> {
         elem
     }
 
+    /// Returns the element (which is a reference &T) at absolute position `index`, or `None` if the
+    /// index is out of range. This is the checked, safe layer above the unchecked `get`.
+    #[inline]
+    pub fn get_checked(&self, index: Index) -> Option<&'a T> {
+        if self.is_index_in_range(index) {
+            Some(unsafe { self.memory.as_ptr().add((index % N) as usize).read() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable handle to the stored reference at absolute position `index`, or `None` if
+    /// the index is out of range. Writing through it replaces which element the slot points at.
+    #[inline]
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut &'a T> {
+        if self.is_index_in_range(index) {
+            Some(unsafe { &mut *self.memory.as_ptr().add((index % N) as usize) })
+        } else {
+            None
+        }
+    }
+
     /// Pushes the given element value to the end of the queue, possibly overwriting elements.
     /// It does not perform any validations before pushing elements.
     /// It may `panic!` if you try to insert more than `Index::MAX` elements.
@@ -120,6 +142,18 @@ impl<'a, T, const N: Index> RingBuffer<'a,T,N> {
         self.head = self.head + 1; // this may panic!
     }
 
+    /// Pushes the given element value to the front of the queue, possibly overwriting elements.
+    /// The `tail` is decremented first and the element is written at the new `tail`.
+    /// It does not perform any validations before pushing elements, so the caller must keep the
+    /// invariant `tail <= head`: pushing at the front past `head` breaks invariant assumptions.
+    /// It may `panic!` if you try to push below `Index::MIN`.
+    #[inline]
+    pub fn push_front(&mut self, elem: &'a T) {
+        self.tail = self.tail - 1; // this may panic!
+        self.put(self.tail, elem);
+        self.len  = self.len + 1;  // this may panic!
+    }
+
     /// Removes an element from the front of the queue.
     /// It does not perform any validations before removing elements.
     /// It may `panic!` if you try to remove elements when the queue is empty.
@@ -133,12 +167,417 @@ impl<'a, T, const N: Index> RingBuffer<'a,T,N> {
         result
     }
 
+    /// Removes an element from the back (or the newest end) of the queue.
+    /// The `head` is decremented first and the element at the new `head` is read and returned.
+    /// It does not perform any validations before removing elements.
+    /// It may `panic!` if you try to remove elements when the queue is empty.
+    /// It may even lead to `head` behind `tail`, which break invariant assumptions.
+    #[inline]
+    pub fn pop_back(&mut self) -> &'a T {
+        self.head = self.head - 1; // this may panic!
+        let result = self.get(self.head);
+        self.len  = self.len - 1;  // this may panic!
+        result
+    }
+
+    /// Returns the occupied region as up to two contiguous slices of stored references,
+    /// split at the wraparound point of the allocation.
+    /// The region starts at `start = tail % N` and ends (exclusive) at `end_excl = head % N`.
+    /// If the window does not wrap around the end of the allocation it is returned as the first
+    /// slice together with an empty second slice; otherwise the `start..N` region is returned as
+    /// the first slice and the `0..end_excl` region as the second.
+    /// No range check is performed, mirroring the low level contract of the other accessors.
+    #[inline]
+    pub fn as_slices(&self) -> (&[&'a T], &[&'a T]) {
+        let start = (self.tail % N) as usize;
+        let end_excl = (self.head % N) as usize;
+        unsafe {
+            let base = self.memory.as_ptr();
+            if self.len == 0 {
+                (&[], &[])
+            } else if start < end_excl {
+                (core::slice::from_raw_parts(base.add(start), end_excl - start), &[])
+            } else {
+                (core::slice::from_raw_parts(base.add(start), (N as usize) - start),
+                 core::slice::from_raw_parts(base, end_excl))
+            }
+        }
+    }
+
+    /// Returns the occupied region as up to two contiguous mutable slices of stored references.
+    /// This is the mutable counterpart of [`as_slices`](Self::as_slices) and follows the exact
+    /// same split rules at the wraparound point; no range check is performed.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [&'a T], &mut [&'a T]) {
+        let start = (self.tail % N) as usize;
+        let end_excl = (self.head % N) as usize;
+        unsafe {
+            let base = self.memory.as_ptr();
+            if self.len == 0 {
+                (&mut [], &mut [])
+            } else if start < end_excl {
+                (core::slice::from_raw_parts_mut(base.add(start), end_excl - start), &mut [])
+            } else {
+                (core::slice::from_raw_parts_mut(base.add(start), (N as usize) - start),
+                 core::slice::from_raw_parts_mut(base, end_excl))
+            }
+        }
+    }
+
+    /// Returns an iterator over the live window, yielding every element (which is a reference &T)
+    /// from `tail` up to (but not including) `head`, oldest first.
+    /// The iterator is double-ended, so `iter().rev()` walks the same window from `head` down to `tail`.
+    /// Each absolute cursor is translated to a slot by performing the cursor modulus divide `capacity`,
+    /// exactly like `get`, so no range check is performed while iterating.
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, T, N> {
+        Iter { memory: self.memory, front: self.tail, back: self.head, _marker: PhantomData::<T> }
+    }
+
+}
+
+
+/// Panicking, ergonomic accessor for the absolute-index window.
+///
+/// Unlike the unchecked `get`, `rb[i]` validates `is_index_in_range(i)` and panics with a clear
+/// out-of-range message otherwise. The index math stays `i % N`.
+impl<'a, T, const N: Index> core::ops::Index<Index> for RingBuffer<'a, T, N> {
+    type Output = &'a T;
+
+    #[inline]
+    fn index(&self, index: Index) -> &&'a T {
+        if !self.is_index_in_range(index) {
+            panic!("RingBuffer index {} out of range", index);
+        }
+        unsafe { &*self.memory.as_ptr().add((index % N) as usize) }
+    }
+}
+
+/// Writable counterpart of the panicking [`Index`](core::ops::Index) accessor. Assigning through
+/// `rb[i]` replaces which element the slot at absolute index `i` points at.
+impl<'a, T, const N: Index> core::ops::IndexMut<Index> for RingBuffer<'a, T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: Index) -> &mut &'a T {
+        if !self.is_index_in_range(index) {
+            panic!("RingBuffer index {} out of range", index);
+        }
+        unsafe { &mut *self.memory.as_ptr().add((index % N) as usize) }
+    }
+}
+
+
+/// Iterator over the live window of a [`RingBuffer`], yielding `&'a T` from `tail` towards `head`.
+///
+/// The forward cursor `front` reads at its current position and advances until it reaches `back`;
+/// the backward cursor `back` decrements from `head` towards `tail`. Both translate the absolute
+/// cursor to a slot by performing the cursor modulus divide `capacity`, exactly like `RingBuffer::get`.
+pub struct Iter<'a, T: Sized, const N: Index> {
+    memory: NonNull<&'a T>,
+    front: Index,
+    back: Index,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T, const N: Index> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front < self.back {
+            let elem = unsafe { self.memory.as_ptr().add((self.front % N) as usize).read() };
+            self.front = self.front + 1;
+            Some(elem)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T, const N: Index> DoubleEndedIterator for Iter<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front < self.back {
+            self.back = self.back - 1;
+            let elem = unsafe { self.memory.as_ptr().add((self.back % N) as usize).read() };
+            Some(elem)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T, const N: Index> ExactSizeIterator for Iter<'a, T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        (self.back - self.front) as usize
+    }
+}
+
+
+/// Owning counterpart of [`RingBuffer`] which stores elements `T` by value instead of references.
+///
+/// Where [`RingBuffer`] keeps pointers to elements owned somewhere else, this variant owns the
+/// elements: `push` moves a value in with `ptr::write`, `pop` moves it out with `ptr::read`, and the
+/// `Drop` implementation runs `drop_in_place` over every occupied slot before freeing the allocation.
+/// As with the low level API, no range check is performed; the caller must keep `tail <= head`.
+pub struct OwnedRingBuffer<T: Sized, const N: Index> {
+    memory: NonNull<T>,
+    capacity: Index,
+    len: Index,
+    head: Index,
+    tail: Index,
+}
+
+impl<T, const N: Index> OwnedRingBuffer<T, N> {
+
+    /// Creates an owning ring buffer of size N
+    pub fn new() -> Self {
+        OwnedRingBuffer::<T, N>::with_capacity(N)
+    }
+
+    fn with_capacity(capacity: Index) -> OwnedRingBuffer<T, N> {
+        let layout = Layout::from_size_align((capacity as usize) * size_of::<T>(), align_of::<T>()).unwrap();
+        let ptr = unsafe { alloc(layout) };
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        } else {
+            OwnedRingBuffer {
+            	memory: NonNull::new(ptr).unwrap().cast(),
+            	len: 0,
+            	capacity,
+            	head: 0,
+            	tail: 0
+            }
+        }
+    }
+
+    /// Returns the maximum capacity of the ring buffer.
+    #[inline]
+    pub fn capacity(&mut self) -> Index { self.capacity }
+
+    /// Returns the actual number of elements in the ring buffer.
+    #[inline]
+    pub fn len(&mut self) -> Index { self.len }
+
+    /// Returns `true` if the ring buffer is empty. Returns `false` otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns `true` if the ring buffer is full. Returns `false` otherwise.
+    #[inline]
+    pub fn is_full(&self) -> bool { self.len >= self.capacity }
+
+    /// Moves the given value to the end of the queue, possibly overwriting elements.
+    /// When the queue is full the oldest occupant (the slot about to be overwritten) is dropped in
+    /// place and `tail` is advanced first, so the live window never exceeds `capacity` slots and the
+    /// `Drop` glue cannot double-drop an evicted value. The new value is then moved into the slot
+    /// with `ptr::write`. No range validation is performed.
+    #[inline]
+    pub fn push(&mut self, elem: T) {
+        if self.is_full() {
+            unsafe { core::ptr::drop_in_place(self.memory.as_ptr().add((self.head % N) as usize)); }
+            self.len  = self.len - 1;
+            self.tail = self.tail + 1;
+        }
+        unsafe { self.memory.as_ptr().add((self.head % N) as usize).write(elem); }
+        self.len  = self.len + 1;  // this may panic!
+        self.head = self.head + 1; // this may panic!
+    }
+
+    /// Moves a value out of the front of the queue with `ptr::read` and returns it.
+    /// The slot is left logically uninitialized and is excluded from the live window afterwards,
+    /// so the subsequent `Drop` never reads it again and no double-drop can occur. No validation
+    /// is performed, so popping an empty queue is undefined behavior.
+    #[inline]
+    pub fn pop(&mut self) -> T {
+        let result = unsafe { self.memory.as_ptr().add((self.tail % N) as usize).read() };
+        self.len  = self.len - 1;  // this may panic!
+        self.tail = self.tail + 1; // this may panic!
+        result
+    }
+
+}
+
+impl<T, const N: Index> Drop for OwnedRingBuffer<T, N> {
+    fn drop(&mut self) {
+        // Drop only the initialized slots, i.e. those inside the `tail..head` window, accounting
+        // for wraparound, then free the allocation with the matching layout.
+        unsafe {
+            let mut cursor = self.tail;
+            while cursor < self.head {
+                core::ptr::drop_in_place(self.memory.as_ptr().add((cursor % N) as usize));
+                cursor = cursor + 1;
+            }
+            let layout = Layout::from_size_align((self.capacity as usize) * size_of::<T>(), align_of::<T>()).unwrap();
+            dealloc(self.memory.as_ptr().cast(), layout);
+        }
+    }
+}
+
+
+/// Growable counterpart of [`RingBuffer`] whose capacity is not pinned to a const generic.
+///
+/// Instead of hardcoding `% N`, `get`/`put` take the modulus from the runtime `capacity`, so the
+/// buffer can grow on demand through [`reserve`](Self::reserve). Growing allocates the next
+/// power-of-two capacity and re-lays-out the live window contiguously from index `0`, unwrapping it
+/// if it previously wrapped around the end of the old allocation. Like the low level API, the
+/// accessors perform no range check.
+pub struct GrowableRingBuffer<'a, T: Sized> {
+    memory: NonNull<&'a T>,
+    _marker: PhantomData<T>,
+    capacity: Index,
+    len: Index,
+    head: Index,
+    tail: Index,
+}
+
+impl<'a, T> GrowableRingBuffer<'a, T> {
+
+    /// The smallest capacity allocated on the first growth.
+    const MIN_CAPACITY: Index = 8;
+
+    /// Creates an empty growable ring buffer. No memory is allocated until the first
+    /// [`reserve`](Self::reserve) (or [`push`](Self::push)) needs it.
+    pub fn new() -> Self {
+        GrowableRingBuffer {
+            memory: NonNull::dangling(),
+            _marker: PhantomData::<T>,
+            capacity: 0,
+            len: 0,
+            head: 0,
+            tail: 0,
+        }
+    }
+
+    /// Returns the current capacity of the ring buffer.
+    #[inline]
+    pub fn capacity(&mut self) -> Index { self.capacity }
+
+    /// Returns the actual number of elements in the ring buffer.
+    #[inline]
+    pub fn len(&mut self) -> Index { self.len }
+
+    /// Returns `true` if the ring buffer is empty. Returns `false` otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns `true` if the ring buffer is full. Returns `false` otherwise.
+    #[inline]
+    pub fn is_full(&self) -> bool { self.len >= self.capacity }
+
+    /// Ensures there is room for at least `additional` more elements.
+    ///
+    /// When `len + additional` exceeds the current capacity, a new buffer of the next power-of-two
+    /// capacity (starting from [`MIN_CAPACITY`](Self::MIN_CAPACITY)) is allocated and the live window
+    /// is copied into it contiguously from index `0`. If the old window wrapped around the end of the
+    /// allocation, the `tail..old_cap` region and the `0..head % old_cap` region are copied one after
+    /// the other so the window becomes linear again; afterwards `tail = 0` and `head = len`.
+    pub fn reserve(&mut self, additional: Index) {
+        let required = self.len + additional;
+        if required <= self.capacity {
+            return;
+        }
+        let mut new_capacity = if self.capacity == 0 { Self::MIN_CAPACITY } else { self.capacity };
+        while new_capacity < required {
+            new_capacity = new_capacity * 2;
+        }
+        let layout = Layout::from_size_align((new_capacity as usize) * size_of::<&T>(), align_of::<&T>()).unwrap();
+        let ptr = unsafe { alloc(layout) };
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+        let dst: NonNull<&'a T> = NonNull::new(ptr).unwrap().cast();
+        // Unwrap-on-grow: copy the live window into the new buffer contiguously from index 0.
+        if self.len > 0 {
+            let old_cap = self.capacity;
+            let mut i: Index = 0;
+            while i < self.len {
+                unsafe {
+                    let elem = self.memory.as_ptr().add(((self.tail + i) % old_cap) as usize).read();
+                    dst.as_ptr().add(i as usize).write(elem);
+                }
+                i = i + 1;
+            }
+            let old_layout = Layout::from_size_align((old_cap as usize) * size_of::<&T>(), align_of::<&T>()).unwrap();
+            unsafe { dealloc(self.memory.as_ptr().cast(), old_layout); }
+        }
+        self.memory = dst;
+        self.capacity = new_capacity;
+        self.tail = 0;
+        self.head = self.len;
+    }
+
+    /// Returns an element (which is a reference &T) at absolute position `index`.
+    /// The actual element position is calculated by performing `index` modulus divide `capacity`.
+    /// Passing an index which is out of range results in undefined behavior.
+    #[inline]
+    pub fn get(&mut self, index: Index) -> &'a T {
+        unsafe { self.memory.as_ptr().add((index % self.capacity) as usize).read() }
+    }
+
+    /// Stores an element (which is a reference &T) at absolute position `index`.
+    /// The actual element position is calculated by performing `index` modulus divide `capacity`.
+    /// Passing an index which is out of range results in undefined behavior.
+    #[inline]
+    pub fn put(&mut self, index: Index, elem: &'a T) -> &'a T {
+        unsafe { self.memory.as_ptr().add((index % self.capacity) as usize).write(elem); }
+        elem
+    }
+
+    /// Pushes the given element value to the end of the queue, growing the buffer when it is full.
+    /// Unlike the fixed-capacity [`RingBuffer`], a full growable buffer reserves more room instead
+    /// of overwriting the oldest element.
+    #[inline]
+    pub fn push(&mut self, elem: &'a T) {
+        if self.is_full() {
+            self.reserve(1);
+        }
+        self.put(self.head, elem);
+        self.len  = self.len + 1;  // this may panic!
+        self.head = self.head + 1; // this may panic!
+    }
+
+    /// Removes an element from the front of the queue.
+    /// It does not perform any validations before removing elements.
+    #[inline]
+    pub fn pop(&mut self) -> &'a T {
+        let result = self.get(self.tail);
+        self.len  = self.len - 1;  // this may panic!
+        self.tail = self.tail + 1; // this may panic!
+        result
+    }
+
+    /// Frees the backing allocation.
+    ///
+    /// Like the baseline [`RingBuffer`], this type only borrows the stored elements and does not
+    /// implement `Drop`: implementing it would make every instance drop-checked and forbid the usual
+    /// pattern of building the buffer before the stack values it references. Callers that want to
+    /// reclaim the heap buffer call `free()` explicitly once they are done with it; a buffer that was
+    /// never grown owns no allocation and `free()` is then a no-op.
+    pub fn free(&mut self) {
+        if self.capacity > 0 {
+            let layout = Layout::from_size_align((self.capacity as usize) * size_of::<&T>(), align_of::<&T>()).unwrap();
+            unsafe { dealloc(self.memory.as_ptr().cast(), layout); }
+            self.memory = NonNull::dangling();
+            self.capacity = 0;
+            self.len = 0;
+            self.head = 0;
+            self.tail = 0;
+        }
+    }
+
 }
 
 
 #[cfg(test)]
 mod tests {
-    use super::{Index, RingBuffer};
+    use super::{GrowableRingBuffer, Index, OwnedRingBuffer, RingBuffer};
 
     #[test]
     fn check_index_size() {
@@ -559,5 +998,298 @@ mod tests {
         assert!(!r.is_index_in_range(6));
     }
 
+    #[test]
+    fn ability_to_iterate_forward_and_backward() {
+        let mut r: RingBuffer<u32, 3> = RingBuffer::new();
+        let v0: u32 = 0;
+        let v1: u32 = 1;
+        let v2: u32 = 2;
+        let p0: &u32 = &v0;
+        let p1: &u32 = &v1;
+        let p2: &u32 = &v2;
+        //--
+        r.push(p0);
+        r.push(p1);
+        r.push(p2);
+        //-- forward walks tail -> head, oldest first
+        let mut it = r.iter();
+        assert!(3 == it.len());
+        assert!((3, Some(3)) == it.size_hint());
+        assert!(p0 == it.next().unwrap());
+        assert!(2 == it.len());
+        assert!(p1 == it.next().unwrap());
+        assert!(p2 == it.next().unwrap());
+        assert!(it.next().is_none());
+        //-- reverse walks head -> tail, newest first
+        let mut rev = r.iter().rev();
+        assert!(p2 == rev.next().unwrap());
+        assert!(p1 == rev.next().unwrap());
+        assert!(p0 == rev.next().unwrap());
+        assert!(rev.next().is_none());
+    }
+
+    #[test]
+    fn ability_to_iterate_after_going_around() {
+        let mut r: RingBuffer<u32, 3> = RingBuffer::new();
+        let v0: u32 = 0;
+        let v1: u32 = 1;
+        let v2: u32 = 2;
+        let v3: u32 = 3;
+        let p0: &u32 = &v0;
+        let p1: &u32 = &v1;
+        let p2: &u32 = &v2;
+        let p3: &u32 = &v3;
+        //-- push three, drop one, push one so the window wraps around the allocation
+        r.push(p0);
+        r.push(p1);
+        r.push(p2);
+        assert!(p0 == r.pop());
+        r.push(p3);
+        //-- the live window is now p1, p2, p3 regardless of wraparound
+        let seen: [&u32; 3] = {
+            let mut it = r.iter();
+            [it.next().unwrap(), it.next().unwrap(), it.next().unwrap()]
+        };
+        assert!(p1 == seen[0]);
+        assert!(p2 == seen[1]);
+        assert!(p3 == seen[2]);
+    }
+
+    #[test]
+    fn ability_to_push_front() {
+        let mut r: RingBuffer<u32, 3> = RingBuffer::new();
+        let v0: u32 = 0;
+        let v1: u32 = 1;
+        let v2: u32 = 2;
+        let p0: &u32 = &v0;
+        let p1: &u32 = &v1;
+        let p2: &u32 = &v2;
+        //-- make room at the front by advancing the tail first
+        r.push(p1);
+        r.push(p2);
+        assert!(p1 == r.pop());
+        assert!(1 == r.len());
+        //-- now a front push reclaims the freed slot without breaking `tail <= head`
+        r.push_front(p0);
+        assert!(2 == r.len());
+        assert!(r.is_index_in_range(0));
+        assert!(r.is_index_in_range(1));
+        assert!(p0 == r.tail());
+        assert!(p0 == r.get(0));
+        assert!(p2 == r.get(1));
+    }
+
+    #[test]
+    fn ability_to_pop_back() {
+        let mut r: RingBuffer<u32, 3> = RingBuffer::new();
+        let v0: u32 = 0;
+        let v1: u32 = 1;
+        let v2: u32 = 2;
+        let p0: &u32 = &v0;
+        let p1: &u32 = &v1;
+        let p2: &u32 = &v2;
+        //--
+        r.push(p0);
+        r.push(p1);
+        r.push(p2);
+        assert!(3 == r.len());
+        //-- popping the back removes the newest element
+        assert!(p2 == r.pop_back());
+        assert!(2 == r.len());
+        assert!(r.is_index_in_range(0));
+        assert!(r.is_index_in_range(1));
+        assert!(!r.is_index_in_range(2));
+        //--
+        assert!(p1 == r.pop_back());
+        assert!(1 == r.len());
+        assert!(r.is_index_in_range(0));
+        assert!(!r.is_index_in_range(1));
+        //-- the remaining element is still reachable from the front
+        assert!(p0 == r.pop());
+        assert!(0 == r.len());
+    }
+
+    #[test]
+    fn ability_to_view_as_slices_without_wraparound() {
+        let mut r: RingBuffer<u32, 3> = RingBuffer::new();
+        let v0: u32 = 0;
+        let v1: u32 = 1;
+        let p0: &u32 = &v0;
+        let p1: &u32 = &v1;
+        //--
+        r.push(p0);
+        r.push(p1);
+        let (first, second) = r.as_slices();
+        assert!(2 == first.len());
+        assert!(0 == second.len());
+        assert!(p0 == first[0]);
+        assert!(p1 == first[1]);
+    }
+
+    #[test]
+    fn ability_to_view_as_slices_with_wraparound() {
+        let mut r: RingBuffer<u32, 3> = RingBuffer::new();
+        let v0: u32 = 0;
+        let v1: u32 = 1;
+        let v2: u32 = 2;
+        let v3: u32 = 3;
+        let p0: &u32 = &v0;
+        let p1: &u32 = &v1;
+        let p2: &u32 = &v2;
+        let p3: &u32 = &v3;
+        //-- force the window to wrap across the end of the allocation
+        r.push(p0);
+        r.push(p1);
+        r.push(p2);
+        assert!(p0 == r.pop());
+        r.push(p3);
+        //-- tail slot is 1, head slot is 1 -> region is [1..3] followed by [0..1]
+        let (first, second) = r.as_slices();
+        assert!(2 == first.len());
+        assert!(1 == second.len());
+        assert!(p1 == first[0]);
+        assert!(p2 == first[1]);
+        assert!(p3 == second[0]);
+    }
+
+    #[test]
+    fn ability_to_own_push_and_pop() {
+        let mut r: OwnedRingBuffer<u32, 3> = OwnedRingBuffer::new();
+        assert!(0 == r.len());
+        r.push(10);
+        r.push(20);
+        r.push(30);
+        assert!(3 == r.len());
+        assert!(r.is_full());
+        //-- values move out in FIFO order
+        assert!(10 == r.pop());
+        assert!(20 == r.pop());
+        assert!(30 == r.pop());
+        assert!(0 == r.len());
+        assert!(r.is_empty());
+    }
+
+    #[test]
+    fn ability_to_drop_only_live_slots() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Noisy;
+        impl Drop for Noisy {
+            fn drop(&mut self) { DROPS.fetch_add(1, Ordering::SeqCst); }
+        }
+
+        DROPS.store(0, Ordering::SeqCst);
+        {
+            let mut r: OwnedRingBuffer<Noisy, 3> = OwnedRingBuffer::new();
+            r.push(Noisy);
+            r.push(Noisy);
+            r.push(Noisy);
+            //-- this value is moved out and dropped by the caller, not by the buffer
+            let moved = r.pop();
+            drop(moved);
+            assert!(1 == DROPS.load(Ordering::SeqCst));
+        }
+        //-- dropping the buffer drops exactly the two remaining live slots (no double-drop of the popped one)
+        assert!(3 == DROPS.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn ability_to_overwrite_on_full_without_double_drop() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Noisy;
+        impl Drop for Noisy {
+            fn drop(&mut self) { DROPS.fetch_add(1, Ordering::SeqCst); }
+        }
+
+        DROPS.store(0, Ordering::SeqCst);
+        {
+            let mut r: OwnedRingBuffer<Noisy, 3> = OwnedRingBuffer::new();
+            r.push(Noisy);
+            r.push(Noisy);
+            r.push(Noisy);
+            //-- a fourth push into a full N=3 buffer evicts and drops exactly one oldest occupant
+            r.push(Noisy);
+            assert!(1 == DROPS.load(Ordering::SeqCst));
+            assert!(3 == r.len());
+        }
+        //-- the three still-live occupants are dropped once each; the evicted one is not dropped again
+        assert!(4 == DROPS.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn ability_to_grow_from_empty() {
+        let mut r: GrowableRingBuffer<u32> = GrowableRingBuffer::new();
+        assert!(0 == r.capacity());
+        r.reserve(1);
+        //-- first growth jumps to the minimum capacity
+        assert!(8 == r.capacity());
+        //-- a larger request rounds up to the next power of two
+        r.reserve(10);
+        assert!(16 == r.capacity());
+    }
+
+    #[test]
+    fn ability_to_unwrap_the_window_on_grow() {
+        //-- borrowed values are declared before the buffer so they outlive every reference it holds
+        let v: [u32; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let extra: u32 = 100;
+        let mut r: GrowableRingBuffer<u32> = GrowableRingBuffer::new();
+        //-- fill past the initial capacity so the window has wrapped once, then grow again
+        for i in 0..8 {
+            r.push(&v[i]);
+        }
+        //-- advance the tail so the live window straddles the end of the allocation
+        assert!(&v[0] == r.pop());
+        assert!(&v[1] == r.pop());
+        r.push(&v[8]);
+        r.push(&v[9]);
+        assert!(8 == r.len());
+        assert!(8 == r.capacity());
+        //-- the next push triggers a grow that must re-linearize the wrapped window
+        r.push(&extra);
+        assert!(16 == r.capacity());
+        assert!(9 == r.len());
+        //-- elements come back out in the order they were logically stored
+        for expected in 2..10u32 {
+            assert!(expected == *r.pop());
+        }
+        assert!(100 == *r.pop());
+        assert!(0 == r.len());
+    }
+
+    #[test]
+    fn ability_to_index_and_get_checked() {
+        let mut r: RingBuffer<u32, 3> = RingBuffer::new();
+        let v0: u32 = 0;
+        let v1: u32 = 1;
+        let p0: &u32 = &v0;
+        let p1: &u32 = &v1;
+        //--
+        r.push(p0);
+        r.push(p1);
+        //-- the panicking operator form returns the stored reference for valid indices
+        assert!(p0 == r[0]);
+        assert!(p1 == r[1]);
+        //-- the checked form mirrors it but yields Option instead of panicking
+        assert!(Some(p0) == r.get_checked(0));
+        assert!(Some(p1) == r.get_checked(1));
+        assert!(r.get_checked(2).is_none());
+        assert!(r.get_mut(2).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range_panics() {
+        let mut r: RingBuffer<u32, 3> = RingBuffer::new();
+        let v0: u32 = 0;
+        r.push(&v0);
+        let _ = r[1];
+    }
+
 }
 